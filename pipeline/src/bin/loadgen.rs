@@ -0,0 +1,39 @@
+//! Standalone bursty load generator for the telemetry pipeline.
+//!
+//! Usage: loadgen [--addr 127.0.0.1:20777] [--mbps 10] [--duration-secs 10]
+use pipeline::generator::{LoadGenerator, LoadGeneratorConfig};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+
+    let mut config = LoadGeneratorConfig::default();
+    if let Some(addr) = arg_value(&args, "--addr") {
+        config.target_addr = addr.parse()?;
+    }
+    if let Some(mbps) = arg_value(&args, "--mbps").and_then(|v| v.parse().ok()) {
+        config.target_mbps = mbps;
+    }
+    let duration_secs: u64 = arg_value(&args, "--duration-secs")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(10);
+
+    println!(
+        "[LOADGEN] Sending to {} at {:.1}Mbps for {}s (3s initial burst at 2x rate)",
+        config.target_addr, config.target_mbps, duration_secs
+    );
+
+    let generator = LoadGenerator::new(config);
+    let sent = generator.run(Duration::from_secs(duration_secs)).await?;
+
+    println!("[LOADGEN] Done, sent {} packets", sent);
+    Ok(())
+}
+
+fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
@@ -0,0 +1,104 @@
+//! HTTP control/query API: `GET /metrics` for a JSON snapshot, `POST /shutdown`
+//! for graceful termination, `POST /reset` to zero the counters mid-session.
+use crate::metrics::Metrics;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::Serialize;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+
+#[derive(Serialize)]
+struct MetricsSnapshot {
+    packets_received: u64,
+    packets_processed: u64,
+    packets_dropped: u64,
+    packets_shed: u64,
+    bytes_received: u64,
+    loss_rate_pct: f64,
+    shed_rate_pct: f64,
+    latency_mean_ms: f64,
+    latency_median_ms: f64,
+    latency_p99_ms: f64,
+    queue_depth: usize,
+    throughput_pps: f64,
+}
+
+impl From<&Metrics> for MetricsSnapshot {
+    fn from(m: &Metrics) -> Self {
+        let (mean_ms, median_ms, p99_ms) = m.latency_stats();
+        Self {
+            packets_received: m.packets_received,
+            packets_processed: m.packets_processed,
+            packets_dropped: m.packets_dropped,
+            packets_shed: m.packets_shed,
+            bytes_received: m.bytes_received,
+            loss_rate_pct: m.packet_loss_rate(),
+            shed_rate_pct: m.shed_rate(),
+            latency_mean_ms: mean_ms,
+            latency_median_ms: median_ms,
+            latency_p99_ms: p99_ms,
+            queue_depth: m.total_queue_depth(),
+            throughput_pps: m.throughput_pps(),
+        }
+    }
+}
+
+/// Serve the control API until `shutdown_rx` fires, tied into the same
+/// shutdown signal the main loop uses so operators can drive a graceful
+/// stop over HTTP instead of only via Ctrl+C.
+pub async fn run_control_api(
+    bind_addr: SocketAddr,
+    metrics: Arc<RwLock<Metrics>>,
+    shutdown_tx: broadcast::Sender<()>,
+    mut shutdown_rx: broadcast::Receiver<()>,
+) {
+    let make_svc = make_service_fn(move |_conn| {
+        let metrics = Arc::clone(&metrics);
+        let shutdown_tx = shutdown_tx.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                handle(req, Arc::clone(&metrics), shutdown_tx.clone())
+            }))
+        }
+    });
+
+    let server = Server::bind(&bind_addr).serve(make_svc);
+    println!("[CONTROL] HTTP control API: http://{}", bind_addr);
+
+    let graceful = server.with_graceful_shutdown(async move {
+        let _ = shutdown_rx.recv().await;
+    });
+
+    if let Err(e) = graceful.await {
+        eprintln!("[CONTROL] Server error: {}", e);
+    }
+}
+
+async fn handle(
+    req: Request<Body>,
+    metrics: Arc<RwLock<Metrics>>,
+    shutdown_tx: broadcast::Sender<()>,
+) -> Result<Response<Body>, Infallible> {
+    match (req.method(), req.uri().path()) {
+        (&Method::GET, "/metrics") => {
+            let snapshot = MetricsSnapshot::from(&*metrics.read().await);
+            let body = serde_json::to_string(&snapshot).unwrap_or_else(|_| "{}".to_string());
+            Ok(Response::new(Body::from(body)))
+        }
+        (&Method::POST, "/shutdown") => {
+            let _ = shutdown_tx.send(());
+            Ok(Response::new(Body::from(r#"{"status":"shutting down"}"#)))
+        }
+        (&Method::POST, "/reset") => {
+            *metrics.write().await = Metrics::new();
+            Ok(Response::new(Body::from(r#"{"status":"reset"}"#)))
+        }
+        _ => {
+            let mut response = Response::new(Body::from("not found"));
+            *response.status_mut() = StatusCode::NOT_FOUND;
+            Ok(response)
+        }
+    }
+}
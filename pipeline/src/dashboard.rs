@@ -5,6 +5,7 @@ use axum::{
     routing::get,
     Router,
 };
+use crate::stats_stream::StatsSnapshot;
 use serde::Serialize;
 use tokio::sync::broadcast;
 use tokio::time::{interval, Duration};
@@ -46,19 +47,36 @@ impl From<&crate::telemetry::TelemetryPacket> for DashboardData {
     }
 }
 
-pub async fn start_dashboard(tx: broadcast::Sender<DashboardData>) {
-    let app = Router::new()
-        .route("/", get(index))
+pub async fn start_dashboard(
+    tx: broadcast::Sender<DashboardData>,
+    stats_tx: broadcast::Sender<StatsSnapshot>,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    let telemetry_routes = Router::new()
         .route("/ws", get(websocket_handler))
         .with_state(tx);
 
+    let stats_routes = Router::new()
+        .route("/stats-ws", get(stats_websocket_handler))
+        .with_state(stats_tx);
+
+    let app = Router::new()
+        .route("/", get(index))
+        .merge(telemetry_routes)
+        .merge(stats_routes);
+
     let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
         .await
         .unwrap();
-    
+
     println!(" [DASHBOARD] F1 Telemetry Dashboard: http://127.0.0.1:8080");
-    
-    axum::serve(listener, app).await.unwrap();
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            let _ = shutdown.recv().await;
+        })
+        .await
+        .unwrap();
 }
 
 async fn index() -> Html<&'static str> {
@@ -74,9 +92,40 @@ async fn websocket_handler(
 
 async fn handle_socket(mut socket: WebSocket, tx: broadcast::Sender<DashboardData>) {
     let mut rx = tx.subscribe();
-    
+
     let mut ping_interval = interval(Duration::from_secs(30));
-    
+
+    loop {
+        tokio::select! {
+            Ok(data) = rx.recv() => {
+                let json = serde_json::to_string(&data).unwrap();
+                if socket.send(Message::Text(json)).await.is_err() {
+                    break;
+                }
+            }
+            _ = ping_interval.tick() => {
+                if socket.send(Message::Ping(vec![])).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Same shape as `/ws` but streams `StatsSnapshot`s for the trend charts
+/// instead of per-packet telemetry.
+async fn stats_websocket_handler(
+    ws: WebSocketUpgrade,
+    axum::extract::State(tx): axum::extract::State<broadcast::Sender<StatsSnapshot>>,
+) -> impl IntoResponse {
+    ws.on_upgrade(|socket| handle_stats_socket(socket, tx))
+}
+
+async fn handle_stats_socket(mut socket: WebSocket, tx: broadcast::Sender<StatsSnapshot>) {
+    let mut rx = tx.subscribe();
+
+    let mut ping_interval = interval(Duration::from_secs(30));
+
     loop {
         tokio::select! {
             Ok(data) = rx.recv() => {
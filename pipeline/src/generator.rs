@@ -0,0 +1,212 @@
+//! Synthetic bursty load generator for exercising the UDP ingest path
+//! without an external F1 sender.
+use crate::reassembly::FragmentHeader;
+use crate::telemetry::TelemetryPacket;
+use rand::Rng;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::time::{sleep, Instant};
+
+/// How long the generator runs at double rate before settling to steady
+/// state, long enough to meaningfully push the receive buffer/queues.
+const BURST_DURATION_SECS: u64 = 3;
+
+pub struct LoadGeneratorConfig {
+    pub target_addr: SocketAddr,
+    pub datagram_size: usize,
+    pub target_mbps: f64,
+}
+
+impl Default for LoadGeneratorConfig {
+    fn default() -> Self {
+        Self {
+            target_addr: "127.0.0.1:20777".parse().unwrap(),
+            datagram_size: 256,
+            target_mbps: 10.0,
+        }
+    }
+}
+
+/// Emits fixed-size datagrams on a schedule derived from `target_mbps`,
+/// doubling the send rate for the first `BURST_DURATION_SECS` before
+/// dropping back to the steady-state cadence.
+pub struct LoadGenerator {
+    config: LoadGeneratorConfig,
+}
+
+impl LoadGenerator {
+    pub fn new(config: LoadGeneratorConfig) -> Self {
+        Self { config }
+    }
+
+    fn steady_interval(&self) -> Duration {
+        let bits_per_sec = self.config.target_mbps * 1_000_000.0;
+        let bytes_per_sec = bits_per_sec / 8.0;
+        let packets_per_sec = bytes_per_sec / self.config.datagram_size as f64;
+        Duration::from_secs_f64(1.0 / packets_per_sec.max(1.0))
+    }
+
+    /// Run for `duration`, sending synthetic datagrams until it elapses.
+    pub async fn run(&self, duration: Duration) -> Result<u64, String> {
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .await
+            .map_err(|e| format!("Failed to bind generator socket: {}", e))?;
+
+        let steady_interval = self.steady_interval();
+        let burst_interval = steady_interval / 2; // double rate during the burst
+
+        let start = Instant::now();
+        let mut sent = 0u64;
+        let mut rng = rand::thread_rng();
+
+        while start.elapsed() < duration {
+            let interval = if start.elapsed() < Duration::from_secs(BURST_DURATION_SECS) {
+                burst_interval
+            } else {
+                steady_interval
+            };
+
+            let packet = synthetic_packet(self.config.datagram_size, &mut rng);
+            socket
+                .send_to(&packet, self.config.target_addr)
+                .await
+                .map_err(|e| format!("Send failed: {}", e))?;
+            sent += 1;
+
+            sleep(interval).await;
+        }
+
+        Ok(sent)
+    }
+}
+
+/// Build a datagram padded up to `size` bytes (one whose header and body
+/// already exceed `size` is sent unpadded and larger) carrying a
+/// `FragmentHeader` (a single
+/// complete fragment, since this generator never splits a packet across
+/// datagrams) followed by a format header (see `telemetry::FormatHeader`)
+/// and a real msgpack-map body, so it survives reassembly and decodes the
+/// same way a genuine F1 telemetry packet would.
+fn synthetic_packet(size: usize, rng: &mut impl Rng) -> Vec<u8> {
+    let body = rmp_serde::to_vec_named(&TelemetryPacket {
+        t: 0,
+        id: rng.gen(),
+        p: rng.gen_range(0..3), // spread across critical/high/normal lanes
+        spd: rng.gen_range(0..340),
+        thr: 0.5,
+        brk: 0.0,
+        str: 0.0,
+        g: 4,
+        rpm: 10_000,
+        drs: false,
+        oilp: 4.5,
+        oilt: 105,
+        h2ot: 95,
+        tp: vec![23.0, 23.0, 21.5, 21.5],
+        tt: vec![95, 95, 90, 90],
+        ers: 2_000_000.0,
+        mguk: 120_000.0,
+        fuel: 1.6,
+    })
+    .expect("TelemetryPacket always serializes");
+
+    let mut packet = Vec::with_capacity(size);
+    packet.extend_from_slice(
+        &FragmentHeader {
+            packet_id: rng.gen(),
+            frag_index: 0,
+            frag_count: 1,
+        }
+        .to_bytes(),
+    );
+    packet.extend_from_slice(&2023u16.to_be_bytes()); // packetFormat
+    packet.push(rng.gen()); // packetId
+    packet.extend_from_slice(&body);
+    packet.resize(size.max(packet.len()), 0); // pad to the target datagram size
+    packet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Metrics;
+    use crate::processor::{LaneRouter, LaneRouterConfig, PacketDecoder};
+    use crate::reassembly::Reassembler;
+    use crate::supervisor::Supervisor;
+    use crate::telemetry::FastTelemetry;
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    /// Drive the generator's 2x burst into a local receiver wired up to the
+    /// real reassembly/decode/route path, then confirm the lane queues the
+    /// burst piled up in fully drain once the send rate settles back down -
+    /// the pipeline-recovers guarantee the generator exists to exercise.
+    #[tokio::test]
+    async fn recovers_after_burst_settles() {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+
+        let metrics = Arc::new(RwLock::new(Metrics::new()));
+        let mut supervisor = Supervisor::new();
+        let router = LaneRouter::new(
+            Arc::clone(&metrics),
+            true,
+            &mut supervisor,
+            LaneRouterConfig::default(),
+        );
+        let decoder = PacketDecoder::new(false);
+        let mut reassembler = Reassembler::new();
+
+        let config = LoadGeneratorConfig {
+            target_addr: addr,
+            datagram_size: 128,
+            target_mbps: 8.0,
+        };
+        let run_duration = Duration::from_secs(BURST_DURATION_SECS + 2);
+
+        let generator = LoadGenerator::new(config);
+        let send_task = tokio::spawn(async move { generator.run(run_duration).await });
+
+        let mut buf = vec![0u8; 2048];
+        let recv_deadline = Instant::now() + run_duration;
+        while Instant::now() < recv_deadline {
+            let (len, _) = match tokio::time::timeout(
+                Duration::from_millis(200),
+                socket.recv_from(&mut buf),
+            )
+            .await
+            {
+                Ok(Ok(recvd)) => recvd,
+                _ => continue,
+            };
+
+            let (header, fragment_payload) = match FragmentHeader::from_bytes(&buf[..len]) {
+                Ok(parsed) => parsed,
+                Err(_) => continue,
+            };
+            let reassembled = match reassembler.insert(header, fragment_payload) {
+                Some(data) => data,
+                None => continue,
+            };
+            let (body, _packet_format) = match decoder.decode_raw(&reassembled) {
+                Ok(decoded) => decoded,
+                Err(_) => continue,
+            };
+            let priority = FastTelemetry::new(&body).priority().unwrap_or(1);
+            let _ = router.route(priority, body).await;
+        }
+
+        let sent = send_task.await.unwrap().unwrap();
+        assert!(sent > 0, "generator did not send any packets");
+
+        // give the lane workers a moment to drain whatever the burst queued up
+        sleep(Duration::from_secs(1)).await;
+
+        let depth_after_settle = metrics.read().await.total_queue_depth();
+        assert_eq!(
+            depth_after_settle, 0,
+            "lane queues never drained after the burst settled"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+//! Library surface for the F1 telemetry pipeline.
+//!
+//! Exists so criterion benchmarks and the load generator binary can exercise
+//! the decode/process path directly instead of going through `main`.
+pub mod telemetry;
+pub mod processor;
+pub mod metrics;
+pub mod dashboard;
+pub mod reassembly;
+pub mod statsd;
+pub mod generator;
+pub mod supervisor;
+pub mod control;
+pub mod stats_stream;
+#[cfg(feature = "opentelemetry")]
+pub mod otel;
+#[cfg(feature = "quic")]
+pub mod quic;
@@ -1,13 +1,19 @@
 //! F1 Telemetry Processing Pipeline
-mod telemetry;
-mod processor;
-mod metrics;
-mod dashboard;
+use pipeline::{control, dashboard, metrics, processor, reassembly, statsd, stats_stream, supervisor};
+#[cfg(feature = "opentelemetry")]
+use pipeline::otel;
+#[cfg(feature = "quic")]
+use pipeline::quic;
 
 use tokio::sync::broadcast;
 use dashboard::DashboardData;
-use processor::{TelemetryProcessor, PacketDecoder};
+use processor::{LaneRouter, LaneRouterConfig, PacketDecoder};
 use metrics::Metrics;
+use pipeline::telemetry::FastTelemetry;
+use reassembly::{FragmentHeader, Reassembler};
+use statsd::{StatsdConfig, StatsdSink};
+use supervisor::Supervisor;
+use stats_stream::StatsStream;
 
 use std::sync::Arc;
 use tokio::net::UdpSocket;
@@ -16,8 +22,18 @@ use tokio::time::{interval, timeout, Duration};
 use tokio::signal;
 
 const UDP_PORT: u16 = 20777;
+const CONTROL_PORT: u16 = 9000;
 const BUFFER_SIZE: usize = 2048;
 const INACTIVITY_TIMEOUT_SECS: u64 = 5;
+const REASSEMBLY_TIMEOUT_SECS: u64 = 2;
+
+/// Find `--flag value` in the CLI args and return `value`.
+fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -41,47 +57,126 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     let metrics = Arc::new(RwLock::new(Metrics::new()));
     let metrics_clone = Arc::clone(&metrics);
+
+    #[cfg(feature = "opentelemetry")]
+    {
+        let otlp_endpoint = std::env::var("OTLP_ENDPOINT")
+            .unwrap_or_else(|_| "http://localhost:4317".to_string());
+        match otel::OtelExporter::new(&otlp_endpoint, Arc::clone(&metrics)) {
+            Ok(exporter) => {
+                println!("[OTEL] Exporting metrics to {}", otlp_endpoint);
+                Arc::new(exporter).spawn_collector(Arc::clone(&metrics));
+            }
+            Err(e) => eprintln!("[OTEL] Failed to start exporter: {}", e),
+        }
+    }
     
+    let mut supervisor = Supervisor::new();
+
     let (dashboard_tx, _) = broadcast::channel::<DashboardData>(100);
     let dashboard_tx_clone = dashboard_tx.clone();
-    
-    tokio::spawn(async move {
-        dashboard::start_dashboard(dashboard_tx_clone).await;
+
+    let stats_stream = Arc::new(StatsStream::new());
+    let stats_tx = stats_stream.sender();
+
+    supervisor.spawn("dashboard", move |shutdown_rx| {
+        dashboard::start_dashboard(dashboard_tx_clone, stats_tx, shutdown_rx)
     });
 
-    let mut processor = TelemetryProcessor::new(Arc::clone(&metrics), simulate_load);
+    let control_addr: std::net::SocketAddr = arg_value(&args, "--control-addr")
+        .unwrap_or_else(|| format!("127.0.0.1:{}", CONTROL_PORT))
+        .parse()?;
+    let control_metrics = Arc::clone(&metrics);
+    let control_shutdown_tx = supervisor.shutdown_handle();
+    supervisor.spawn("control-api", move |shutdown_rx| {
+        control::run_control_api(control_addr, control_metrics, control_shutdown_tx, shutdown_rx)
+    });
+
+    let statsd_config = StatsdConfig {
+        host: arg_value(&args, "--statsd-host").unwrap_or_else(|| StatsdConfig::default().host),
+        port: arg_value(&args, "--statsd-port")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or_else(|| StatsdConfig::default().port),
+        prefix: arg_value(&args, "--statsd-prefix").unwrap_or_else(|| StatsdConfig::default().prefix),
+    };
+    println!("[STATSD] Shipping metrics to {}:{} (prefix={})", statsd_config.host, statsd_config.port, statsd_config.prefix);
+    StatsdSink::spawn(statsd_config).spawn_collector(Arc::clone(&metrics));
+
+    Arc::clone(&stats_stream).spawn_publisher(Arc::clone(&metrics), Duration::from_secs(1), &mut supervisor);
+
+    let router = LaneRouter::new(
+        Arc::clone(&metrics),
+        simulate_load,
+        &mut supervisor,
+        LaneRouterConfig::default(),
+    );
     let decoder = PacketDecoder::new(simulate_load);
 
+    #[cfg(feature = "quic")]
+    {
+        let quic_processor = Arc::new(tokio::sync::Mutex::new(processor::TelemetryProcessor::new(
+            Arc::clone(&metrics),
+            simulate_load,
+        )));
+        let quic_metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let config = quic::QuicIngestConfig::default();
+            if let Err(e) = quic::run_quic_server(config, quic_processor, quic_metrics).await {
+                eprintln!("[QUIC] Server exited: {}", e);
+            }
+        });
+    }
+
     let socket = UdpSocket::bind(format!("127.0.0.1:{}", UDP_PORT)).await?;
     println!("\n[UDP] Listening on port {}", UDP_PORT);
     println!("[INFO] Waiting for telemetry stream...\n");
     
-    let metrics_handle = tokio::spawn(async move {
+    supervisor.spawn("metrics", move |mut shutdown_rx| async move {
         let mut interval = interval(Duration::from_secs(2));
         loop {
-            interval.tick().await;
-            let metrics = metrics_clone.read().await;
-            if metrics.packets_received > 0 {
-                metrics.print_summary();
+            tokio::select! {
+                _ = interval.tick() => {
+                    let metrics = metrics_clone.read().await;
+                    if metrics.packets_received > 0 {
+                        metrics.print_summary();
+                    }
+                }
+                _ = shutdown_rx.recv() => break,
             }
         }
     });
     
     let shutdown = signal::ctrl_c();
     tokio::pin!(shutdown);
-    
+    let mut control_shutdown_rx = supervisor.subscribe();
+
     let mut buffer = vec![0u8; BUFFER_SIZE];
     let mut decisions_made = 0u64;
     let mut dashboard_counter = 0u32;
-    
+    let mut reassembler = Reassembler::new();
+    let mut reassembly_sweep = interval(Duration::from_secs(REASSEMBLY_TIMEOUT_SECS));
+
     loop {
         tokio::select! {
             _ = &mut shutdown => {
                 println!("\n[SHUTDOWN] Received Ctrl+C, shutting down gracefully...");
                 break;
             }
-            
-            result = timeout(Duration::from_secs(INACTIVITY_TIMEOUT_SECS), 
+
+            _ = control_shutdown_rx.recv() => {
+                println!("\n[SHUTDOWN] Received control API shutdown request...");
+                break;
+            }
+
+            _ = reassembly_sweep.tick() => {
+                let abandoned = reassembler.evict_expired(Duration::from_secs(REASSEMBLY_TIMEOUT_SECS));
+                if abandoned > 0 {
+                    let mut m = metrics.write().await;
+                    m.packets_dropped += abandoned as u64;
+                }
+            }
+
+            result = timeout(Duration::from_secs(INACTIVITY_TIMEOUT_SECS),
                            socket.recv_from(&mut buffer)) => {
                 match result {
                     Ok(Ok((len, _addr))) => {
@@ -90,9 +185,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             m.packets_received += 1;
                             m.bytes_received += len as u64;
                         }
-                        
+                        stats_stream.record_packet(len).await;
+
+                        // reassemble datagram-sized fragments into full packets
+                        let (header, fragment_payload) = match FragmentHeader::from_bytes(&buffer[..len]) {
+                            Ok(parsed) => parsed,
+                            Err(e) => {
+                                if decisions_made % 100 == 0 {
+                                    eprintln!("❌ [CORRUPT] {}", e);
+                                }
+                                let mut m = metrics.write().await;
+                                m.packets_dropped += 1;
+                                continue;
+                            }
+                        };
+                        let reassembled = match reassembler.insert(header, fragment_payload) {
+                            Some(data) => data,
+                            None => continue, // waiting on remaining fragments
+                        };
+
                         // raw bytes for zero-copy processing
-                        let raw_data = match decoder.decode_raw(&buffer[..len]) {
+                        let (raw_data, packet_format) = match decoder.decode_raw(&reassembled) {
                             Ok(d) => d,
                             Err(e) => {
                                 if decisions_made % 100 == 0 {
@@ -103,24 +216,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 continue;
                             }
                         };
-                        
-                        match processor.process_packet_zero_copy(raw_data.clone()).await {
+
+                        let priority = FastTelemetry::new(&raw_data).priority().unwrap_or(1);
+
+                        match router.route(priority, raw_data.clone()).await {
                             Ok(_) => {
                                 // only deserialize for dashboard every Nth packet
                                 dashboard_counter += 1;
                                 if dashboard_counter % 10 == 0 {  // send 1/10th to dashboard
-                                    if let Ok(packet) = decoder.decode_full(&raw_data) {
+                                    if let Ok(packet) = decoder.decode_full(&raw_data, packet_format) {
                                         let dashboard_data = DashboardData::from(&packet);
                                         let _ = dashboard_tx.send(dashboard_data);
                                     }
                                 }
-                                
+
                                 if decisions_made % 5000 == 0 {
-                                    let (used, capacity) = processor.buffer_stats();
-                                    println!("  [BUFFER] {}/{} slots | {} packets (zero-copy)", 
-                                        used, capacity, decisions_made);
+                                    let m = metrics.read().await;
+                                    println!("  [BUFFER] {} queued across lanes | shed rate {:.2}% | {} packets routed",
+                                        m.total_queue_depth(), m.shed_rate(), decisions_made);
                                 }
-                                
+
                                 decisions_made += 1;
                             }
                             Err(e) => {
@@ -143,8 +258,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
     
-    metrics_handle.abort();
-    
+    // stop accepting new packets, then signal every supervised task (dashboard,
+    // metrics, lane workers) to drain in-flight work before the final summary
+    drop(router);
+    supervisor.trigger_shutdown();
+    supervisor.drain().await;
+
     println!("\n{}", "=".repeat(70));
     println!("FINAL STATISTICS");
     println!("{}", "=".repeat(70));
@@ -1,12 +1,92 @@
 //! Performance metrics tracking
 use std::collections::VecDeque;
 
+/// Sliding-window median deglitcher for raw latency samples.
+///
+/// `simulate_processing_work_fast` injects occasional 10x spikes, and real
+/// deployments see the same shape of glitch from scheduler/GC pauses. Feeding
+/// the median of the last `N` samples into `Metrics::add_latency` instead of
+/// the raw value suppresses these isolated outliers while still tracking a
+/// sustained latency increase (which drags the whole window up).
+pub struct Deglitcher {
+    window: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl Deglitcher {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            window: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Push a raw sample and return the median of the current window
+    /// (including samples still warming up, i.e. fewer than `capacity`).
+    pub fn push(&mut self, raw_us: u64) -> u64 {
+        if self.window.len() >= self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back(raw_us);
+
+        let mut sorted: Vec<u64> = self.window.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let mid = sorted.len() / 2;
+        if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        }
+    }
+}
+
+/// The three priority lanes packets get routed into, matching the 0/1/2
+/// priority byte already extracted by `FastTelemetry::priority`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lane {
+    Critical,
+    High,
+    Normal,
+}
+
+impl Lane {
+    pub const ALL: [Lane; 3] = [Lane::Critical, Lane::High, Lane::Normal];
+
+    pub fn from_priority(priority: u8) -> Self {
+        match priority {
+            0 => Lane::Critical,
+            1 => Lane::High,
+            _ => Lane::Normal,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Lane::Critical => "critical",
+            Lane::High => "high",
+            Lane::Normal => "normal",
+        }
+    }
+}
+
+/// Per-lane queue depth and drop count, so the dashboard can show where
+/// congestion is building up rather than one aggregate `packets_dropped`.
+#[derive(Default, Clone, Copy)]
+pub struct LaneStats {
+    pub depth: usize,
+    pub dropped: u64,
+}
+
 pub struct Metrics {
     pub packets_received: u64,
     pub packets_processed: u64,
     pub packets_dropped: u64,
     pub bytes_received: u64,
-    
+    pub transport_errors: u64, // connection/stream errors, distinct from packet corruption
+    pub packets_shed: u64, // load-shed under backpressure, distinct from corrupted/dropped
+    pub lane_stats: [LaneStats; 3], // indexed by Lane::Critical/High/Normal
+
     // latency in microseconds
     latencies: VecDeque<u64>,
     max_samples: usize,
@@ -21,12 +101,23 @@ impl Metrics {
             packets_processed: 0,
             packets_dropped: 0,
             bytes_received: 0,
+            transport_errors: 0,
+            packets_shed: 0,
+            lane_stats: [LaneStats::default(); 3],
             latencies: VecDeque::with_capacity(1000),
             max_samples: 1000,
             start_time: std::time::Instant::now(),
         }
     }
     
+    pub fn lane_stats_mut(&mut self, lane: Lane) -> &mut LaneStats {
+        &mut self.lane_stats[lane as usize]
+    }
+
+    pub fn lane_stats(&self, lane: Lane) -> LaneStats {
+        self.lane_stats[lane as usize]
+    }
+
     pub fn add_latency(&mut self, latency_us: u64) {
         if self.latencies.len() >= self.max_samples {
             self.latencies.pop_front();
@@ -78,18 +169,36 @@ impl Metrics {
             0.0
         }
     }
+
+    pub fn shed_rate(&self) -> f64 {
+        if self.packets_received > 0 {
+            (self.packets_shed as f64 / self.packets_received as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Total packets currently queued across every priority lane, for the
+    /// `[BUFFER]` log line to reflect real backpressure.
+    pub fn total_queue_depth(&self) -> usize {
+        self.lane_stats.iter().map(|s| s.depth).sum()
+    }
     
     pub fn print_summary(&self) {
         let (mean_ms, median_ms, p99_ms) = self.latency_stats();
         let elapsed = self.start_time.elapsed().as_secs_f64();
         
         println!("\n[RUST METRICS] Pipeline Performance:");
-        println!("  Packets: {} received, {} processed, {} dropped", 
-                 self.packets_received, self.packets_processed, self.packets_dropped);
+        println!("  Packets: {} received, {} processed, {} dropped, {} transport errors",
+                 self.packets_received, self.packets_processed, self.packets_dropped, self.transport_errors);
         println!("  Throughput: {:.0} pps", self.throughput_pps());
         println!("  Latency: mean={:.3}ms median={:.3}ms p99={:.3}ms", 
                  mean_ms, median_ms, p99_ms);
-        println!("  Packet loss: {:.2}%", self.packet_loss_rate());
+        println!("  Packet loss: {:.2}%, shed: {:.2}%", self.packet_loss_rate(), self.shed_rate());
+        for lane in Lane::ALL {
+            let stats = self.lane_stats(lane);
+            println!("  Lane[{}]: depth={} dropped={}", lane.as_str(), stats.depth, stats.dropped);
+        }
         println!("  Runtime: {:.1}s", elapsed);
         
         if p99_ms > 10.0 {
@@ -0,0 +1,150 @@
+//! OpenTelemetry/OTLP metrics export (feature-gated, like netapp's optional `telemetry` feature)
+#![cfg(feature = "opentelemetry")]
+
+use crate::metrics::Metrics;
+use opentelemetry::metrics::{Counter, Histogram, Meter, ObservableGauge};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::time::{interval, Duration};
+
+/// Collection cadence for the periodic scrape of `Metrics` into OTel instruments.
+const COLLECTION_INTERVAL_MS: u64 = 1000;
+
+/// Maps the pipeline's counters, gauges and latency stats onto OTel instruments
+/// and exports them over OTLP/gRPC (tonic); there is no HTTP transport.
+pub struct OtelExporter {
+    meter: Meter,
+    // kept alive for the process lifetime - dropping this stops the OTLP
+    // export pipeline the instruments above are bound to
+    _provider: SdkMeterProvider,
+    packets_received: Counter<u64>,
+    packets_processed: Counter<u64>,
+    packets_dropped: Counter<u64>,
+    latency_histogram: Histogram<f64>,
+    _throughput_gauge: ObservableGauge<f64>,
+    _loss_rate_gauge: ObservableGauge<f64>,
+}
+
+impl OtelExporter {
+    /// Build the exporter and register its instruments against the global meter provider.
+    /// `endpoint` is the OTLP collector address, e.g. `http://localhost:4317`.
+    pub fn new(endpoint: &str, metrics: Arc<RwLock<Metrics>>) -> Result<Self, String> {
+        let exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint);
+
+        let provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(exporter)
+            .build()
+            .map_err(|e| format!("Failed to build OTLP metrics pipeline: {}", e))?;
+
+        // install globally so `global::meter` below (and any other ad-hoc
+        // caller) binds to our real exporting provider instead of the no-op
+        // default - otherwise every instrument silently discards its data
+        opentelemetry::global::set_meter_provider(provider.clone());
+
+        let meter = opentelemetry::global::meter("f1-telemetry-pipeline");
+
+        let packets_received = meter
+            .u64_counter("packets_received")
+            .with_description("Total telemetry packets received")
+            .init();
+        let packets_processed = meter
+            .u64_counter("packets_processed")
+            .with_description("Total telemetry packets processed")
+            .init();
+        let packets_dropped = meter
+            .u64_counter("packets_dropped")
+            .with_description("Total telemetry packets dropped")
+            .init();
+        let latency_histogram = meter
+            .f64_histogram("packet_latency_ms")
+            .with_description("Per-packet processing latency")
+            .init();
+
+        let gauge_metrics = Arc::clone(&metrics);
+        let throughput_gauge = meter
+            .f64_observable_gauge("throughput_pps")
+            .with_description("Packets received per second")
+            .with_callback(move |observer| {
+                if let Ok(m) = gauge_metrics.try_read() {
+                    observer.observe(m.throughput_pps(), &[]);
+                }
+            })
+            .init();
+
+        let loss_metrics = Arc::clone(&metrics);
+        let loss_rate_gauge = meter
+            .f64_observable_gauge("packet_loss_rate")
+            .with_description("Percentage of received packets dropped")
+            .with_callback(move |observer| {
+                if let Ok(m) = loss_metrics.try_read() {
+                    observer.observe(m.packet_loss_rate(), &[]);
+                }
+            })
+            .init();
+
+        Ok(Self {
+            meter,
+            _provider: provider,
+            packets_received,
+            packets_processed,
+            packets_dropped,
+            latency_histogram,
+            _throughput_gauge: throughput_gauge,
+            _loss_rate_gauge: loss_rate_gauge,
+        })
+    }
+
+    /// Spawn a periodic task that reads `metrics` and records counter deltas onto
+    /// the OTel instruments, so a scraping backend sees live mean/median/p99.
+    /// The histogram receives only the current mean, median and p99 on each
+    /// tick (tagged by `stat`), not the individual latency samples.
+    pub fn spawn_collector(self: Arc<Self>, metrics: Arc<RwLock<Metrics>>) {
+        tokio::spawn(async move {
+            let mut tick = interval(Duration::from_millis(COLLECTION_INTERVAL_MS));
+            let mut last_received = 0u64;
+            let mut last_processed = 0u64;
+            let mut last_dropped = 0u64;
+
+            loop {
+                tick.tick().await;
+                let m = metrics.read().await;
+
+                let received_delta = m.packets_received.saturating_sub(last_received);
+                let processed_delta = m.packets_processed.saturating_sub(last_processed);
+                let dropped_delta = m.packets_dropped.saturating_sub(last_dropped);
+
+                if received_delta > 0 {
+                    self.packets_received.add(received_delta, &[]);
+                }
+                if processed_delta > 0 {
+                    self.packets_processed.add(processed_delta, &[]);
+                }
+                if dropped_delta > 0 {
+                    self.packets_dropped.add(dropped_delta, &[]);
+                }
+
+                let (mean_ms, median_ms, p99_ms) = m.latency_stats();
+                if mean_ms > 0.0 {
+                    self.latency_histogram.record(mean_ms, &[KeyValue::new("stat", "mean")]);
+                    self.latency_histogram.record(median_ms, &[KeyValue::new("stat", "median")]);
+                    self.latency_histogram.record(p99_ms, &[KeyValue::new("stat", "p99")]);
+                }
+
+                last_received = m.packets_received;
+                last_processed = m.packets_processed;
+                last_dropped = m.packets_dropped;
+            }
+        });
+    }
+
+    /// Exposes the underlying meter for callers that want to add ad-hoc instruments.
+    pub fn meter(&self) -> &Meter {
+        &self.meter
+    }
+}
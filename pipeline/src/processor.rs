@@ -1,12 +1,81 @@
 //! core telemetry processor for performance metrics only
-use crate::metrics::Metrics;
-use crate::telemetry::{FastTelemetry, TelemetryPacket};
+use crate::metrics::{Deglitcher, Lane, Metrics};
+use crate::supervisor::Supervisor;
+use crate::telemetry::{FastTelemetry, FormatHeader, TelemetryPacket};
 use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
 
-const MAX_LATENCY_MS: u64 = 10;
+/// target p99 latency the admission controller steers towards
+const DEFAULT_SETPOINT_MS: f64 = 8.0;
+const DEFAULT_KP: f64 = 0.15;
+const DEFAULT_KI: f64 = 0.05;
+
+/// window size for the median deglitcher applied to raw latency samples
+const DEGLITCH_WINDOW: usize = 5;
+
+/// Closed-loop PI controller that turns the measured p99 latency into a
+/// fraction of low-priority packets to admit, shedding load *before* it's
+/// spent doing work rather than dropping it after the fact.
+pub struct AdmissionController {
+    setpoint_ms: f64,
+    kp: f64,
+    ki: f64,
+    integral: f64,
+    last_error: f64,
+    admit_fraction: f64,
+    last_update: Instant,
+}
+
+impl AdmissionController {
+    pub fn new(setpoint_ms: f64, kp: f64, ki: f64) -> Self {
+        Self {
+            setpoint_ms,
+            kp,
+            ki,
+            integral: 0.0,
+            last_error: 0.0,
+            admit_fraction: 1.0, // start fully open until the first measurement
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Feed a fresh p99 measurement in and recompute the admit fraction.
+    pub fn update(&mut self, measured_p99_ms: f64) -> f64 {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last_update).as_secs_f64().max(0.001);
+        self.last_update = now;
+
+        let error = self.setpoint_ms - measured_p99_ms;
+
+        // decay the integral when the error flips sign so stale windup from a
+        // previous regime doesn't linger into the new one
+        if self.last_error != 0.0 && error.signum() != self.last_error.signum() {
+            self.integral *= 0.5;
+        }
+
+        let candidate_integral = self.integral + error * dt;
+        let candidate_output = self.kp * error + self.ki * candidate_integral;
+
+        // anti-windup: only integrate while doing so wouldn't just push the
+        // already-saturated output further past its clamp
+        let saturated_high = candidate_output > 1.0 && error > 0.0;
+        let saturated_low = candidate_output < 0.0 && error < 0.0;
+        if !saturated_high && !saturated_low {
+            self.integral = candidate_integral;
+        }
+
+        self.admit_fraction = (self.kp * error + self.ki * self.integral).clamp(0.0, 1.0);
+        self.last_error = error;
+        self.admit_fraction
+    }
+
+    pub fn admit_fraction(&self) -> f64 {
+        self.admit_fraction
+    }
+}
 
 pub struct TelemetryProcessor {
     metrics: Arc<RwLock<Metrics>>,
@@ -14,6 +83,8 @@ pub struct TelemetryProcessor {
     buffer_capacity: usize,
     packets_since_last_gc: usize,
     simulate_load: bool,
+    admission: AdmissionController,
+    deglitcher: Deglitcher,
 }
 
 impl TelemetryProcessor {
@@ -24,6 +95,8 @@ impl TelemetryProcessor {
             buffer_capacity: 1000,
             packets_since_last_gc: 0,
             simulate_load,
+            admission: AdmissionController::new(DEFAULT_SETPOINT_MS, DEFAULT_KP, DEFAULT_KI),
+            deglitcher: Deglitcher::new(DEGLITCH_WINDOW),
         }
     }
 
@@ -40,6 +113,26 @@ impl TelemetryProcessor {
 
         let priority = fast_telemetry.priority().unwrap_or(1);
 
+        // admission control: priority 0 (critical) always gets in, everything
+        // else is shed up-front according to the controller's admit fraction
+        if priority != 0 {
+            let admit_fraction = self.admission.admit_fraction();
+            if rand::thread_rng().gen::<f64>() > admit_fraction {
+                // a shed packet does zero work, so it contributes zero
+                // latency; feeding that through keeps the controller ticking
+                // even when no priority-0 traffic ever reaches `update()`
+                // below, so the admit fraction climbs back open once load
+                // actually subsides instead of latching shut forever.
+                self.admission.update(0.0);
+                let mut metrics = self.metrics.write().await;
+                metrics.packets_shed += 1;
+                return Err(format!(
+                    "Packet {} shed - admission controller admit_fraction={:.2}",
+                    packet_id, admit_fraction
+                ));
+            }
+        }
+
         if self.simulate_load {
             // only decode speed for load sim
             if let Ok(speed) = fast_telemetry.speed() {
@@ -48,22 +141,15 @@ impl TelemetryProcessor {
         }
 
         let latency_us = process_start.elapsed().as_micros() as u64;
-        let latency_ms = latency_us as f64 / 1000.0;
-
-        if latency_ms > MAX_LATENCY_MS as f64 {
-            let mut metrics = self.metrics.write().await;
-            metrics.packets_dropped += 1;
-            metrics.add_latency(latency_us);
-            return Err(format!(
-                "Packet {} dropped - latency {:.2}ms > {}ms",
-                packet_id, latency_ms, MAX_LATENCY_MS
-            ));
-        }
+        let deglitched_us = self.deglitcher.push(latency_us);
 
         // update metrics
         let mut metrics = self.metrics.write().await;
         metrics.packets_processed += 1;
-        metrics.add_latency(latency_us);
+        metrics.add_latency(deglitched_us);
+
+        let (_, _, p99_ms) = metrics.latency_stats();
+        self.admission.update(p99_ms);
 
         // store raw bytes in ring buffer (no deserialization)
         if self.packet_buffer.len() >= self.buffer_capacity {
@@ -130,7 +216,10 @@ impl PacketDecoder {
         }
     }
 
-    pub fn decode_raw(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+    /// Strip and validate the leading `packetFormat`/`packetId` header,
+    /// returning the MessagePack body plus the detected format year so the
+    /// dashboard and processor can handle version-specific fields.
+    pub fn decode_raw(&self, data: &[u8]) -> Result<(Vec<u8>, u16), String> {
         if self.simulate_corruption {
             let mut rng = rand::thread_rng();
             if rng.gen_bool(0.001) {
@@ -139,10 +228,176 @@ impl PacketDecoder {
             }
         }
 
-        Ok(data.to_vec())
+        let (header, body) = FormatHeader::peek(data)?;
+        // reject unknown packet formats as corrupt rather than misparsing them;
+        // `is_known_format` avoids allocating the `Box<dyn PacketFormat>`
+        // `format_for` would return here only to immediately drop it
+        if !crate::telemetry::is_known_format(header.packet_format) {
+            return Err(format!("Unsupported packet format {}", header.packet_format));
+        }
+
+        Ok((body.to_vec(), header.packet_format))
+    }
+
+    pub fn decode_full(&self, body: &[u8], packet_format: u16) -> Result<TelemetryPacket, String> {
+        let format = crate::telemetry::format_for(packet_format)?;
+        format.decode(body)
+    }
+}
+
+/// Bounded queue depth for each priority lane. Critical gets the deepest
+/// queue so it is the last to fill up and shed; normal is shallowest since
+/// it's the first to shed under pressure.
+const CRITICAL_LANE_CAPACITY: usize = 512;
+const HIGH_LANE_CAPACITY: usize = 256;
+const NORMAL_LANE_CAPACITY: usize = 128;
+
+/// Once the normal lane's depth crosses the high watermark, new
+/// normal-priority packets are shed up-front instead of being handed to
+/// `try_send` - this is the overload-protection policy that keeps the newest
+/// critical/high (car-telemetry/lap) packets flowing while event packets are
+/// the first thing dropped under backpressure. Shedding continues until the
+/// depth falls back to the low watermark, so the lane doesn't flap in and
+/// out of shedding right at the threshold.
+pub struct LaneRouterConfig {
+    pub normal_high_watermark: usize,
+    pub normal_low_watermark: usize,
+}
+
+impl Default for LaneRouterConfig {
+    fn default() -> Self {
+        Self {
+            normal_high_watermark: (NORMAL_LANE_CAPACITY * 3) / 4,
+            normal_low_watermark: NORMAL_LANE_CAPACITY / 2,
+        }
+    }
+}
+
+/// Routes decoded packets into N bounded per-priority lanes (critical/high/
+/// normal), each backed by its own `mpsc` queue and worker task, so a
+/// backlog of low-priority packets can never delay a critical one. Under
+/// pressure the normal lane sheds first.
+pub struct LaneRouter {
+    senders: [mpsc::Sender<Vec<u8>>; 3],
+    capacities: [usize; 3],
+    metrics: Arc<RwLock<Metrics>>,
+    normal_high_watermark: usize,
+    normal_low_watermark: usize,
+    normal_shedding: AtomicBool,
+}
+
+impl LaneRouter {
+    /// Spawn one worker per lane, each owning its own `TelemetryProcessor`,
+    /// and register it with `supervisor` so dropping the router (closing the
+    /// senders) lets every worker drain its queue and exit cleanly on
+    /// shutdown instead of being aborted mid-packet.
+    pub fn new(
+        metrics: Arc<RwLock<Metrics>>,
+        simulate_load: bool,
+        supervisor: &mut Supervisor,
+        config: LaneRouterConfig,
+    ) -> Self {
+        let capacities = [
+            CRITICAL_LANE_CAPACITY,
+            HIGH_LANE_CAPACITY,
+            NORMAL_LANE_CAPACITY,
+        ];
+
+        let mut senders = Vec::with_capacity(3);
+        for (i, lane) in Lane::ALL.into_iter().enumerate() {
+            let (tx, mut rx) = mpsc::channel::<Vec<u8>>(capacities[i]);
+            let lane_capacity = capacities[i];
+            // weak handle: a strong clone here would keep the lane's channel
+            // open forever and the worker's drain loop below would never end
+            let depth_tx = tx.downgrade();
+            let worker_metrics = Arc::clone(&metrics);
+            let mut worker = TelemetryProcessor::new(Arc::clone(&metrics), simulate_load);
+
+            let lane_name: &'static str = match lane {
+                Lane::Critical => "lane-critical",
+                Lane::High => "lane-high",
+                Lane::Normal => "lane-normal",
+            };
+            supervisor.spawn(lane_name, move |_shutdown| async move {
+                // the lane's own mpsc channel closing (once every sender is
+                // dropped) is this worker's drain signal, not the broadcast
+                while let Some(data) = rx.recv().await {
+                    {
+                        // derive depth from the channel itself rather than a
+                        // counter maintained separately from `route`'s - two
+                        // unsynchronized +1/-1 updates can race and leak a
+                        // permanent off-by-one into `depth`; once the router
+                        // is dropped the depth reads as 0 while draining
+                        let mut m = worker_metrics.write().await;
+                        m.lane_stats_mut(lane).depth = depth_tx
+                            .upgrade()
+                            .map_or(0, |sender| lane_capacity - sender.capacity());
+                    }
+                    if let Err(e) = worker.process_packet_zero_copy(data).await {
+                        eprintln!("[{}] {}", lane.as_str(), e);
+                    }
+                }
+            });
+
+            senders.push(tx);
+        }
+
+        Self {
+            senders: senders.try_into().unwrap_or_else(|_| unreachable!()),
+            capacities,
+            metrics,
+            normal_high_watermark: config.normal_high_watermark,
+            normal_low_watermark: config.normal_low_watermark,
+            normal_shedding: AtomicBool::new(false),
+        }
     }
 
-    pub fn decode_full(&self, data: &[u8]) -> Result<TelemetryPacket, String> {
-        TelemetryPacket::from_bytes(data).map_err(|e| format!("Decode error: {}", e))
+    /// Route one packet by its priority byte, applying overload protection:
+    /// the normal lane sheds proactively once it crosses the high watermark
+    /// (continuing until it drains back to the low watermark), and any lane
+    /// sheds reactively once its queue is outright full. Both cases count as
+    /// `packets_shed`, distinct from corruption (`packets_dropped`).
+    pub async fn route(&self, priority: u8, data: Vec<u8>) -> Result<(), String> {
+        let lane = Lane::from_priority(priority);
+        let sender = &self.senders[lane as usize];
+
+        if lane == Lane::Normal {
+            let depth = self.capacities[lane as usize] - sender.capacity();
+            if depth >= self.normal_high_watermark {
+                self.normal_shedding.store(true, Ordering::Relaxed);
+            } else if depth <= self.normal_low_watermark {
+                self.normal_shedding.store(false, Ordering::Relaxed);
+            }
+
+            if self.normal_shedding.load(Ordering::Relaxed) {
+                let mut m = self.metrics.write().await;
+                m.lane_stats_mut(lane).dropped += 1;
+                m.packets_shed += 1;
+                return Err(format!(
+                    "Lane[{}] over high watermark ({}/{}) - packet shed",
+                    lane.as_str(),
+                    depth,
+                    NORMAL_LANE_CAPACITY
+                ));
+            }
+        }
+
+        match sender.try_send(data) {
+            Ok(()) => {
+                // derive depth from the channel itself (see the worker's
+                // recv loop) rather than an independently-incremented
+                // counter, so a race between this and the worker's
+                // decrement can never leak a permanent +1.
+                let mut m = self.metrics.write().await;
+                m.lane_stats_mut(lane).depth = self.capacities[lane as usize] - sender.capacity();
+                Ok(())
+            }
+            Err(_) => {
+                let mut m = self.metrics.write().await;
+                m.lane_stats_mut(lane).dropped += 1;
+                m.packets_shed += 1;
+                Err(format!("Lane[{}] full - packet shed", lane.as_str()))
+            }
+        }
     }
 }
@@ -0,0 +1,124 @@
+//! QUIC-based ingest transport (feature-gated) with per-stream packet framing
+#![cfg(feature = "quic")]
+
+use crate::metrics::Metrics;
+use crate::processor::TelemetryProcessor;
+use quinn::{Endpoint, ServerConfig, TransportConfig};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock, Semaphore};
+
+/// Each telemetry packet rides its own unidirectional QUIC stream, so
+/// congestion control and loss recovery happen per-packet instead of forcing
+/// head-of-line blocking across the whole connection, unlike plain UDP.
+pub struct QuicIngestConfig {
+    pub bind_addr: SocketAddr,
+    pub max_concurrent_streams: u32,
+}
+
+impl Default for QuicIngestConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: "127.0.0.1:20778".parse().unwrap(),
+            max_concurrent_streams: 256,
+        }
+    }
+}
+
+/// Start the QUIC ingest server and drive it until the process exits.
+/// Accepted connections are handled concurrently; within each connection,
+/// every incoming unidirectional stream is read to completion and handed to
+/// `processor` as a whole packet.
+pub async fn run_quic_server(
+    config: QuicIngestConfig,
+    processor: Arc<Mutex<TelemetryProcessor>>,
+    metrics: Arc<RwLock<Metrics>>,
+) -> Result<(), String> {
+    let server_config = build_server_config(config.max_concurrent_streams)?;
+    let endpoint = Endpoint::server(server_config, config.bind_addr)
+        .map_err(|e| format!("Failed to bind QUIC endpoint: {}", e))?;
+
+    println!("[QUIC] Listening on {}", config.bind_addr);
+
+    while let Some(connecting) = endpoint.accept().await {
+        let processor = Arc::clone(&processor);
+        let metrics = Arc::clone(&metrics);
+        let stream_permits = Arc::new(Semaphore::new(config.max_concurrent_streams as usize));
+
+        tokio::spawn(async move {
+            let connection = match connecting.await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("[QUIC] Connection failed: {}", e);
+                    metrics.write().await.transport_errors += 1;
+                    return;
+                }
+            };
+
+            loop {
+                match connection.accept_uni().await {
+                    Ok(mut recv_stream) => {
+                        let permit = match Arc::clone(&stream_permits).try_acquire_owned() {
+                            Ok(permit) => permit,
+                            Err(_) => {
+                                metrics.write().await.transport_errors += 1;
+                                continue;
+                            }
+                        };
+
+                        let processor = Arc::clone(&processor);
+                        let metrics = Arc::clone(&metrics);
+                        tokio::spawn(async move {
+                            let _permit = permit;
+                            match recv_stream.read_to_end(64 * 1024).await {
+                                Ok(data) => {
+                                    {
+                                        let mut m = metrics.write().await;
+                                        m.packets_received += 1;
+                                        m.bytes_received += data.len() as u64;
+                                    }
+                                    let mut proc = processor.lock().await;
+                                    if let Err(e) = proc.process_packet_zero_copy(data).await {
+                                        eprintln!("[QUIC] {}", e);
+                                    }
+                                }
+                                Err(e) => {
+                                    eprintln!("[QUIC] Stream read failed: {}", e);
+                                    metrics.write().await.transport_errors += 1;
+                                }
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        eprintln!("[QUIC] Connection closed: {}", e);
+                        metrics.write().await.transport_errors += 1;
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Self-signed cert for local/dev use; production deployments should supply
+/// a real certificate chain via a future config option.
+fn build_server_config(max_concurrent_streams: u32) -> Result<ServerConfig, String> {
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".into()])
+        .map_err(|e| format!("Failed to generate self-signed cert: {}", e))?;
+    let cert_der = cert.serialize_der().map_err(|e| e.to_string())?;
+    let key_der = cert.serialize_private_key_der();
+
+    let cert_chain = vec![quinn::rustls::Certificate(cert_der)];
+    let key = quinn::rustls::PrivateKey(key_der);
+
+    let mut server_config = ServerConfig::with_single_cert(cert_chain, key)
+        .map_err(|e| format!("Failed to build QUIC server config: {}", e))?;
+
+    let mut transport = TransportConfig::default();
+    transport.max_concurrent_uni_streams(max_concurrent_streams.into());
+    server_config.transport = Arc::new(transport);
+
+    Ok(server_config)
+}
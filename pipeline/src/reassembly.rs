@@ -0,0 +1,108 @@
+//! Fragmentation/reassembly for telemetry packets larger than one datagram
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Prepended to every wire chunk so the receiver can put packets back
+/// together regardless of arrival order.
+#[derive(Debug, Clone, Copy)]
+pub struct FragmentHeader {
+    pub packet_id: u32,
+    pub frag_index: u16,
+    pub frag_count: u16,
+}
+
+const HEADER_LEN: usize = 4 + 2 + 2;
+
+impl FragmentHeader {
+    pub fn to_bytes(self) -> [u8; HEADER_LEN] {
+        let mut buf = [0u8; HEADER_LEN];
+        buf[0..4].copy_from_slice(&self.packet_id.to_be_bytes());
+        buf[4..6].copy_from_slice(&self.frag_index.to_be_bytes());
+        buf[6..8].copy_from_slice(&self.frag_count.to_be_bytes());
+        buf
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<(Self, &[u8]), String> {
+        if data.len() < HEADER_LEN {
+            return Err("Fragment too short for header".to_string());
+        }
+        let packet_id = u32::from_be_bytes(data[0..4].try_into().unwrap());
+        let frag_index = u16::from_be_bytes(data[4..6].try_into().unwrap());
+        let frag_count = u16::from_be_bytes(data[6..8].try_into().unwrap());
+        if frag_count == 0 || frag_index >= frag_count {
+            return Err(format!(
+                "Invalid fragment index {} of {}",
+                frag_index, frag_count
+            ));
+        }
+        Ok((
+            Self {
+                packet_id,
+                frag_index,
+                frag_count,
+            },
+            &data[HEADER_LEN..],
+        ))
+    }
+}
+
+struct PartialPacket {
+    frag_count: u16,
+    fragments: HashMap<u16, Vec<u8>>,
+    first_seen: Instant,
+}
+
+/// Buffers incoming fragments by `packet_id` and emits the concatenated
+/// payload once every fragment has arrived. Duplicate fragments are an
+/// idempotent insert (last write wins), and packets that never complete are
+/// reclaimed by `evict_expired`.
+pub struct Reassembler {
+    partials: HashMap<u32, PartialPacket>,
+}
+
+impl Reassembler {
+    pub fn new() -> Self {
+        Self {
+            partials: HashMap::new(),
+        }
+    }
+
+    /// Feed in one wire chunk. Returns the fully reassembled payload once the
+    /// last fragment of its packet_id arrives.
+    pub fn insert(&mut self, header: FragmentHeader, payload: &[u8]) -> Option<Vec<u8>> {
+        let partial = self.partials.entry(header.packet_id).or_insert_with(|| PartialPacket {
+            frag_count: header.frag_count,
+            fragments: HashMap::new(),
+            first_seen: Instant::now(),
+        });
+
+        // a fragment disagreeing on frag_count is ignored, so every stored
+        // index stays below the packet's frag_count
+        if header.frag_count != partial.frag_count {
+            return None;
+        }
+        partial.fragments.insert(header.frag_index, payload.to_vec());
+
+        if partial.fragments.len() as u16 == partial.frag_count {
+            let partial = self.partials.remove(&header.packet_id).unwrap();
+            let mut data = Vec::new();
+            for i in 0..partial.frag_count {
+                // presence of every index was just confirmed by the length check above
+                data.extend_from_slice(&partial.fragments[&i]);
+            }
+            return Some(data);
+        }
+
+        None
+    }
+
+    /// Sweep packets whose first fragment arrived more than `timeout` ago and
+    /// never completed. Returns how many abandoned packets were evicted, so
+    /// the caller can count them as dropped.
+    pub fn evict_expired(&mut self, timeout: Duration) -> usize {
+        let before = self.partials.len();
+        self.partials
+            .retain(|_, partial| partial.first_seen.elapsed() < timeout);
+        before - self.partials.len()
+    }
+}
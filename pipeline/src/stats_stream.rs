@@ -0,0 +1,151 @@
+//! Rolling time-series statistics: per-window rates, jitter, and a sliding
+//! P99, published on a `broadcast` channel so the dashboard can plot trends
+//! instead of only ever seeing a cumulative FINAL STATISTICS block.
+use crate::metrics::Metrics;
+use crate::supervisor::Supervisor;
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio::time::{interval, Duration, Instant};
+
+const DEFAULT_JITTER_ALPHA: f64 = 0.2;
+
+/// One window's worth of rolling stats.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsSnapshot {
+    pub packets_per_sec: f64,
+    pub bytes_per_sec: f64,
+    pub drops_per_sec: f64,
+    pub sheds_per_sec: f64,
+    pub jitter_ms: f64,
+    pub p99_ms: f64, // sliding-window p99 from Metrics::latency_stats, not cumulative
+}
+
+/// Smoothed mean absolute deviation of packet inter-arrival gaps (an EWMA of
+/// the successive-gap deviation, in the spirit of RFC 3550 jitter).
+struct JitterTracker {
+    last_arrival: Option<Instant>,
+    last_gap_ms: Option<f64>,
+    jitter_ewma_ms: f64,
+    alpha: f64,
+}
+
+impl JitterTracker {
+    fn new(alpha: f64) -> Self {
+        Self {
+            last_arrival: None,
+            last_gap_ms: None,
+            jitter_ewma_ms: 0.0,
+            alpha,
+        }
+    }
+
+    fn record_arrival(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_arrival {
+            let gap_ms = now.duration_since(last).as_secs_f64() * 1000.0;
+            if let Some(prev_gap_ms) = self.last_gap_ms {
+                let deviation = (gap_ms - prev_gap_ms).abs();
+                self.jitter_ewma_ms = self.alpha * deviation + (1.0 - self.alpha) * self.jitter_ewma_ms;
+            }
+            self.last_gap_ms = Some(gap_ms);
+        }
+        self.last_arrival = Some(now);
+    }
+}
+
+struct StatsInner {
+    jitter: JitterTracker,
+    window_bytes: u64,
+}
+
+/// Feeds per-packet arrival events in from the recv loop and periodically
+/// publishes a `StatsSnapshot` derived from them plus `Metrics` deltas.
+pub struct StatsStream {
+    inner: Mutex<StatsInner>,
+    tx: broadcast::Sender<StatsSnapshot>,
+}
+
+impl StatsStream {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(100);
+        Self {
+            inner: Mutex::new(StatsInner {
+                jitter: JitterTracker::new(DEFAULT_JITTER_ALPHA),
+                window_bytes: 0,
+            }),
+            tx,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<StatsSnapshot> {
+        self.tx.subscribe()
+    }
+
+    /// A cloneable handle to the publish side, for wiring into the
+    /// dashboard's websocket route.
+    pub fn sender(&self) -> broadcast::Sender<StatsSnapshot> {
+        self.tx.clone()
+    }
+
+    /// Call once per received packet from the recv loop to feed the jitter
+    /// tracker and the windowed byte counter.
+    pub async fn record_packet(&self, bytes: usize) {
+        let mut inner = self.inner.lock().await;
+        inner.window_bytes += bytes as u64;
+        inner.jitter.record_arrival();
+    }
+
+    /// Spawn the periodic publisher, registered with `supervisor` so it
+    /// drains on shutdown like every other background task.
+    pub fn spawn_publisher(
+        self: Arc<Self>,
+        metrics: Arc<RwLock<Metrics>>,
+        window: Duration,
+        supervisor: &mut Supervisor,
+    ) {
+        supervisor.spawn("stats-stream", move |mut shutdown_rx| async move {
+            let mut tick = interval(window);
+            let mut last_received = 0u64;
+            let mut last_dropped = 0u64;
+            let mut last_shed = 0u64;
+            let window_secs = window.as_secs_f64();
+
+            loop {
+                tokio::select! {
+                    _ = tick.tick() => {
+                        let (received_delta, dropped_delta, shed_delta, p99_ms) = {
+                            let m = metrics.read().await;
+                            let received_delta = m.packets_received.saturating_sub(last_received);
+                            let dropped_delta = m.packets_dropped.saturating_sub(last_dropped);
+                            let shed_delta = m.packets_shed.saturating_sub(last_shed);
+                            let (_, _, p99_ms) = m.latency_stats();
+                            last_received = m.packets_received;
+                            last_dropped = m.packets_dropped;
+                            last_shed = m.packets_shed;
+                            (received_delta, dropped_delta, shed_delta, p99_ms)
+                        };
+
+                        let (window_bytes, jitter_ms) = {
+                            let mut inner = self.inner.lock().await;
+                            let bytes = inner.window_bytes;
+                            inner.window_bytes = 0;
+                            (bytes, inner.jitter.jitter_ewma_ms)
+                        };
+
+                        let snapshot = StatsSnapshot {
+                            packets_per_sec: received_delta as f64 / window_secs,
+                            bytes_per_sec: window_bytes as f64 / window_secs,
+                            drops_per_sec: dropped_delta as f64 / window_secs,
+                            sheds_per_sec: shed_delta as f64 / window_secs,
+                            jitter_ms,
+                            p99_ms,
+                        };
+                        let _ = self.tx.send(snapshot);
+                    }
+                    _ = shutdown_rx.recv() => break,
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,138 @@
+//! StatsD/Cadence-style async metrics sink
+use crate::metrics::Metrics;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::sync::{mpsc, RwLock};
+use tokio::time::interval;
+
+/// Default MTU-safe datagram size for the batched flush (conservative,
+/// matches the usual Ethernet-minus-headers budget StatsD clients use).
+const DEFAULT_MTU_BYTES: usize = 1432;
+const FLUSH_INTERVAL_MS: u64 = 1000;
+const COLLECTION_INTERVAL_MS: u64 = 1000;
+
+pub struct StatsdConfig {
+    pub host: String,
+    pub port: u16,
+    pub prefix: String,
+}
+
+impl Default for StatsdConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8125,
+            prefix: "f1".to_string(),
+        }
+    }
+}
+
+/// Non-blocking handle callers use to enqueue StatsD lines. The actual UDP
+/// socket and batching live in a dedicated task, so the hot path (the UDP
+/// recv loop) never blocks on the sink.
+#[derive(Clone)]
+pub struct StatsdSink {
+    tx: mpsc::UnboundedSender<String>,
+    prefix: String,
+}
+
+impl StatsdSink {
+    /// Start the batching flush task and return a sink handle to feed it.
+    pub fn spawn(config: StatsdConfig) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+        let prefix = config.prefix.clone();
+
+        tokio::spawn(async move {
+            let socket = match UdpSocket::bind("0.0.0.0:0").await {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("[STATSD] Failed to bind UDP socket: {}", e);
+                    return;
+                }
+            };
+            let target = format!("{}:{}", config.host, config.port);
+
+            let mut buffer = String::new();
+            let mut flush_tick = interval(Duration::from_millis(FLUSH_INTERVAL_MS));
+
+            loop {
+                tokio::select! {
+                    line = rx.recv() => {
+                        let Some(line) = line else { break };
+                        if !buffer.is_empty() && buffer.len() + 1 + line.len() > DEFAULT_MTU_BYTES {
+                            flush(&socket, &target, &mut buffer).await;
+                        }
+                        if !buffer.is_empty() {
+                            buffer.push('\n');
+                        }
+                        buffer.push_str(&line);
+                    }
+                    _ = flush_tick.tick() => {
+                        flush(&socket, &target, &mut buffer).await;
+                    }
+                }
+            }
+
+            flush(&socket, &target, &mut buffer).await;
+        });
+
+        Self { tx, prefix }
+    }
+
+    pub fn counter(&self, name: &str, value: u64) {
+        let _ = self.tx.send(format!("{}.{}:{}|c", self.prefix, name, value));
+    }
+
+    pub fn timer_ms(&self, name: &str, value_ms: f64) {
+        let _ = self.tx.send(format!("{}.{}:{:.3}|ms", self.prefix, name, value_ms));
+    }
+
+    /// Spawn a periodic task that reads `metrics` deltas/percentiles and
+    /// ships them as counters/timers to this sink.
+    pub fn spawn_collector(self, metrics: Arc<RwLock<Metrics>>) {
+        tokio::spawn(async move {
+            let mut tick = interval(Duration::from_millis(COLLECTION_INTERVAL_MS));
+            let mut last_received = 0u64;
+            let mut last_dropped = 0u64;
+            let mut last_bytes = 0u64;
+
+            loop {
+                tick.tick().await;
+                let m = metrics.read().await;
+
+                let received_delta = m.packets_received.saturating_sub(last_received);
+                let dropped_delta = m.packets_dropped.saturating_sub(last_dropped);
+                let bytes_delta = m.bytes_received.saturating_sub(last_bytes);
+
+                if received_delta > 0 {
+                    self.counter("packets.received", received_delta);
+                }
+                if dropped_delta > 0 {
+                    self.counter("packets.dropped", dropped_delta);
+                }
+                if bytes_delta > 0 {
+                    self.counter("bytes.received", bytes_delta);
+                }
+
+                let (_, p50_ms, p99_ms) = m.latency_stats();
+                self.timer_ms("latency.p50", p50_ms);
+                self.timer_ms("latency.p99", p99_ms);
+
+                last_received = m.packets_received;
+                last_dropped = m.packets_dropped;
+                last_bytes = m.bytes_received;
+            }
+        });
+    }
+}
+
+async fn flush(socket: &UdpSocket, target: &str, buffer: &mut String) {
+    if buffer.is_empty() {
+        return;
+    }
+    if let Err(e) = socket.send_to(buffer.as_bytes(), target).await {
+        eprintln!("[STATSD] Flush failed: {}", e);
+    }
+    buffer.clear();
+}
@@ -0,0 +1,64 @@
+//! Lightweight background-task supervisor with graceful drain on shutdown.
+//!
+//! On shutdown every registered task gets a chance to finish its in-flight
+//! work (e.g. a dashboard broadcast) instead of being aborted. Tasks started
+//! with bare `tokio::spawn` (the StatsD sink and its collector, the OTLP
+//! collector, the QUIC server) are not registered here and are not drained.
+use std::future::Future;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+pub struct Supervisor {
+    shutdown: broadcast::Sender<()>,
+    handles: Vec<(&'static str, JoinHandle<()>)>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        let (shutdown, _) = broadcast::channel(1);
+        Self {
+            shutdown,
+            handles: Vec::new(),
+        }
+    }
+
+    /// Hand out a shutdown receiver without registering a task, for callers
+    /// that need to plumb the signal into a library (e.g. axum's graceful
+    /// shutdown) instead of owning the `tokio::spawn` themselves.
+    pub fn subscribe(&self) -> broadcast::Receiver<()> {
+        self.shutdown.subscribe()
+    }
+
+    /// A cloneable handle to the shutdown signal itself, for callers (like
+    /// the HTTP control API's `POST /shutdown`) that need to *trigger* a
+    /// shutdown from outside the owning task rather than just observe it.
+    pub fn shutdown_handle(&self) -> broadcast::Sender<()> {
+        self.shutdown.clone()
+    }
+
+    /// Spawn `task`, handing it a shutdown receiver it's expected to select
+    /// on and exit when it fires.
+    pub fn spawn<F, Fut>(&mut self, name: &'static str, task: F)
+    where
+        F: FnOnce(broadcast::Receiver<()>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let rx = self.shutdown.subscribe();
+        let handle = tokio::spawn(task(rx));
+        self.handles.push((name, handle));
+    }
+
+    /// Signal every registered task to stop accepting new work and drain.
+    pub fn trigger_shutdown(&self) {
+        let _ = self.shutdown.send(());
+    }
+
+    /// Await every registered task's completion, in registration order.
+    pub async fn drain(self) {
+        for (name, handle) in self.handles {
+            if let Err(e) = handle.await {
+                eprintln!("[SUPERVISOR] Task '{}' did not shut down cleanly: {}", name, e);
+            }
+        }
+    }
+}